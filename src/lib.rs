@@ -1,9 +1,21 @@
 use aic_sdk::{EnhancementParameter, Model as AicModel, ModelType, Vad as AicVad, VadParameter};
+use neon::event::Channel;
 use neon::prelude::*;
 use neon::types::buffer::TypedArray;
+use neon::types::{Deferred, JsPromise};
 use std::cell::RefCell;
 use std::sync::{Arc, Mutex};
 
+use sample_format::{f32_to_i16, i16_to_f32};
+
+mod model;
+mod processor;
+mod processor_context;
+mod sample_format;
+mod stream_processor;
+mod vad_context;
+mod wav_file;
+
 // ============================================================================
 // SDK Version
 // ============================================================================
@@ -47,6 +59,10 @@ fn parse_model_type(cx: &mut FunctionContext, value: Handle<JsValue>) -> NeonRes
 const ENHANCEMENT_PARAM_BYPASS: i32 = 0;
 const ENHANCEMENT_PARAM_ENHANCEMENT_LEVEL: i32 = 1;
 const ENHANCEMENT_PARAM_VOICE_GAIN: i32 = 2;
+// `VoiceGate` is implemented entirely at the binding layer (see `GateState`), so these
+// two are read/written directly by JsModel rather than passed through to `AicModel`.
+const ENHANCEMENT_PARAM_VOICE_GATE_THRESHOLD: i32 = 3;
+const ENHANCEMENT_PARAM_VOICE_GATE_HOLD_MS: i32 = 4;
 
 fn parse_enhancement_parameter(
     cx: &mut FunctionContext,
@@ -85,12 +101,200 @@ fn parse_vad_parameter(
     }
 }
 
+// ============================================================================
+// Off-thread Processing Worker
+// ============================================================================
+
+enum ModelJob {
+    ProcessInterleaved {
+        data: Vec<f32>,
+        deferred: Deferred,
+        channel: Channel,
+    },
+    ProcessPlanar {
+        channels: Vec<Vec<f32>>,
+        deferred: Deferred,
+        channel: Channel,
+    },
+}
+
+struct ModelWorker {
+    sender: crossbeam_channel::Sender<ModelJob>,
+}
+
+impl ModelWorker {
+    fn spawn(model: Arc<Mutex<AicModel>>, gate: Arc<Mutex<ModelGate>>) -> Self {
+        let (sender, receiver) = crossbeam_channel::unbounded::<ModelJob>();
+
+        std::thread::spawn(move || {
+            for job in receiver {
+                match job {
+                    ModelJob::ProcessInterleaved {
+                        mut data,
+                        deferred,
+                        channel,
+                    } => {
+                        let result = {
+                            let mut model = model.lock().unwrap();
+                            model.process_interleaved(&mut data).map(|_| data)
+                        };
+                        let result = result.map(|mut data| {
+                            gate.lock().unwrap().apply_voice_gate(&mut data);
+                            data
+                        });
+
+                        deferred.settle_with(&channel, move |mut cx| match result {
+                            Ok(data) => {
+                                let mut out = cx.typed_array(data.len())?;
+                                out.as_mut_slice(&mut cx).copy_from_slice(&data);
+                                Ok(out)
+                            }
+                            Err(e) => cx.throw_error(format!("Failed to process audio: {}", e)),
+                        });
+                    }
+                    ModelJob::ProcessPlanar {
+                        mut channels,
+                        deferred,
+                        channel,
+                    } => {
+                        let result = {
+                            let mut model = model.lock().unwrap();
+                            let mut slice_refs: Vec<&mut [f32]> =
+                                channels.iter_mut().map(|c| c.as_mut_slice()).collect();
+                            model.process_planar(&mut slice_refs).map(|_| ())
+                        };
+                        let result = result.map(|_| {
+                            let gain = gate.lock().unwrap().step_voice_gate();
+                            if gain < 1.0 {
+                                for chan in channels.iter_mut() {
+                                    for sample in chan.iter_mut() {
+                                        *sample *= gain;
+                                    }
+                                }
+                            }
+                            channels
+                        });
+
+                        deferred.settle_with(&channel, move |mut cx| match result {
+                            Ok(channels) => {
+                                let out = JsArray::new(&mut cx, channels.len());
+                                for (i, chan) in channels.iter().enumerate() {
+                                    let mut out_chan = cx.typed_array(chan.len())?;
+                                    out_chan.as_mut_slice(&mut cx).copy_from_slice(chan);
+                                    out.set(&mut cx, i as u32, out_chan)?;
+                                }
+                                Ok(out)
+                            }
+                            Err(e) => {
+                                cx.throw_error(format!("Failed to process planar audio: {}", e))
+                            }
+                        });
+                    }
+                }
+            }
+        });
+
+        ModelWorker { sender }
+    }
+}
+
 // ============================================================================
 // Model Class
 // ============================================================================
 
-pub struct JsModel {
+struct GateState {
+    threshold: f32,
+    hold_ms: f32,
+    gain: f32,
+    hold_blocks_remaining: usize,
+}
+
+impl GateState {
+    fn new() -> Self {
+        GateState {
+            threshold: 0.0,
+            hold_ms: 0.0,
+            gain: 1.0,
+            hold_blocks_remaining: 0,
+        }
+    }
+}
+
+struct ModelGate {
+    gate_vad: AicVad,
+    gate: GateState,
+    sample_rate: u32,
+    num_frames: usize,
+}
+
+impl ModelGate {
+    fn new(gate_vad: AicVad) -> Self {
+        ModelGate {
+            gate_vad,
+            gate: GateState::new(),
+            sample_rate: 0,
+            num_frames: 0,
+        }
+    }
+
+    fn gate_hold_blocks(&self) -> usize {
+        if self.sample_rate == 0 || self.num_frames == 0 {
+            return 0;
+        }
+        let block_ms = self.num_frames as f32 / self.sample_rate as f32 * 1000.0;
+        (self.gate.hold_ms / block_ms).ceil() as usize
+    }
+
+    fn step_voice_gate(&mut self) -> f32 {
+        if self.gate.threshold <= 0.0 {
+            return 1.0;
+        }
+
+        let probability = self.gate_vad.speech_probability();
+        if probability >= self.gate.threshold {
+            self.gate.hold_blocks_remaining = self.gate_hold_blocks();
+        } else if self.gate.hold_blocks_remaining > 0 {
+            self.gate.hold_blocks_remaining -= 1;
+        }
+
+        let target_gain = if probability >= self.gate.threshold || self.gate.hold_blocks_remaining > 0 {
+            1.0
+        } else {
+            0.0
+        };
+
+        const GATE_RAMP_STEP: f32 = 0.1;
+        if target_gain > self.gate.gain {
+            self.gate.gain = (self.gate.gain + GATE_RAMP_STEP).min(target_gain);
+        } else if target_gain < self.gate.gain {
+            self.gate.gain = (self.gate.gain - GATE_RAMP_STEP).max(target_gain);
+        }
+
+        self.gate.gain
+    }
+
+    fn apply_voice_gate(&mut self, audio: &mut [f32]) {
+        let gain = self.step_voice_gate();
+        if gain < 1.0 {
+            for sample in audio.iter_mut() {
+                *sample *= gain;
+            }
+        }
+    }
+}
+
+struct ModelInner {
+    model_type: ModelType,
+    license: String,
     model: Arc<Mutex<AicModel>>,
+    worker: ModelWorker,
+    gate: Arc<Mutex<ModelGate>>,
+    scratch: Vec<f32>,
+    planar_scratch: Vec<Vec<f32>>,
+}
+
+pub struct JsModel {
+    inner: Option<ModelInner>,
 }
 
 impl Finalize for JsModel {}
@@ -101,18 +305,102 @@ impl JsModel {
         let model_type = parse_model_type(&mut cx, model_type_arg)?;
         let license = cx.argument::<JsString>(1)?.value(&mut cx);
 
-        let model = AicModel::new(model_type, &license)
+        let inner = JsModel::new_inner(&mut cx, model_type, license)?;
+
+        Ok(cx.boxed(RefCell::new(JsModel { inner: Some(inner) })))
+    }
+
+    fn new_inner(
+        cx: &mut FunctionContext,
+        model_type: ModelType,
+        license: String,
+    ) -> NeonResult<ModelInner> {
+        let mut model = AicModel::new(model_type, &license)
             .or_else(|e| cx.throw_error(format!("Failed to create model: {}", e)))?;
 
+        let gate_vad = model.create_vad();
+        let gate = Arc::new(Mutex::new(ModelGate::new(gate_vad)));
+
+        let model = Arc::new(Mutex::new(model));
+        let worker = ModelWorker::spawn(Arc::clone(&model), Arc::clone(&gate));
+
+        Ok(ModelInner {
+            model_type,
+            license,
+            model,
+            worker,
+            gate,
+            scratch: Vec::new(),
+            planar_scratch: Vec::new(),
+        })
+    }
+
+    fn js_free(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
+        this.borrow_mut().inner.take();
+        Ok(cx.undefined())
+    }
+
+    fn js_clone(mut cx: FunctionContext) -> JsResult<JsBox<RefCell<JsModel>>> {
+        let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
+        let this = this.borrow();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+
+        let model_type = inner.model_type.clone();
+        let license = inner.license.clone();
+        let (gate_threshold, gate_hold_ms) = {
+            let gate = inner.gate.lock().unwrap();
+            (gate.gate.threshold, gate.gate.hold_ms)
+        };
+
+        let (bypass, enhancement_level, voice_gain) = {
+            let model = inner.model.lock().unwrap();
+            (
+                model
+                    .parameter(EnhancementParameter::Bypass)
+                    .or_else(|e| cx.throw_error(format!("Failed to get parameter: {}", e)))?,
+                model
+                    .parameter(EnhancementParameter::EnhancementLevel)
+                    .or_else(|e| cx.throw_error(format!("Failed to get parameter: {}", e)))?,
+                model
+                    .parameter(EnhancementParameter::VoiceGain)
+                    .or_else(|e| cx.throw_error(format!("Failed to get parameter: {}", e)))?,
+            )
+        };
+
+        let cloned = JsModel::new_inner(&mut cx, model_type, license)?;
+        {
+            let mut gate = cloned.gate.lock().unwrap();
+            gate.gate.threshold = gate_threshold;
+            gate.gate.hold_ms = gate_hold_ms;
+        }
+        {
+            let mut model = cloned.model.lock().unwrap();
+            model
+                .set_parameter(EnhancementParameter::Bypass, bypass)
+                .or_else(|e| cx.throw_error(format!("Failed to set parameter: {}", e)))?;
+            model
+                .set_parameter(EnhancementParameter::EnhancementLevel, enhancement_level)
+                .or_else(|e| cx.throw_error(format!("Failed to set parameter: {}", e)))?;
+            model
+                .set_parameter(EnhancementParameter::VoiceGain, voice_gain)
+                .or_else(|e| cx.throw_error(format!("Failed to set parameter: {}", e)))?;
+        }
+
         Ok(cx.boxed(RefCell::new(JsModel {
-            model: Arc::new(Mutex::new(model)),
+            inner: Some(cloned),
         })))
     }
 
     fn js_optimal_sample_rate(mut cx: FunctionContext) -> JsResult<JsNumber> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
         let this = this.borrow();
-        let model = this.model.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let model = inner.model.lock().unwrap();
 
         let sample_rate = model
             .optimal_sample_rate()
@@ -126,7 +414,10 @@ impl JsModel {
         let sample_rate = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
 
         let this = this.borrow();
-        let model = this.model.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let model = inner.model.lock().unwrap();
 
         let num_frames = model
             .optimal_num_frames(sample_rate)
@@ -142,20 +433,31 @@ impl JsModel {
         let num_frames = cx.argument::<JsNumber>(3)?.value(&mut cx) as usize;
         let allow_variable_frames = cx.argument::<JsBoolean>(4)?.value(&mut cx);
 
-        let this = this.borrow();
-        let mut model = this.model.lock().unwrap();
+        let mut this = this.borrow_mut();
+        let Some(inner) = this.inner.as_mut() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let mut model = inner.model.lock().unwrap();
 
         model
             .initialize(sample_rate, num_channels, num_frames, allow_variable_frames)
             .or_else(|e| cx.throw_error(format!("Failed to initialize model: {}", e)))?;
 
+        drop(model);
+        let mut gate = inner.gate.lock().unwrap();
+        gate.sample_rate = sample_rate;
+        gate.num_frames = num_frames;
+
         Ok(cx.undefined())
     }
 
     fn js_output_delay(mut cx: FunctionContext) -> JsResult<JsNumber> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
         let this = this.borrow();
-        let model = this.model.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let model = inner.model.lock().unwrap();
 
         let delay = model
             .output_delay()
@@ -167,7 +469,10 @@ impl JsModel {
     fn js_reset(mut cx: FunctionContext) -> JsResult<JsUndefined> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
         let this = this.borrow();
-        let mut model = this.model.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let mut model = inner.model.lock().unwrap();
 
         model
             .reset()
@@ -179,11 +484,28 @@ impl JsModel {
     fn js_set_parameter(mut cx: FunctionContext) -> JsResult<JsUndefined> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
         let parameter_arg = cx.argument::<JsValue>(1)?;
-        let parameter = parse_enhancement_parameter(&mut cx, parameter_arg)?;
+        let param_num = parameter_arg.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx) as i32;
         let value = cx.argument::<JsNumber>(2)?.value(&mut cx) as f32;
 
-        let this = this.borrow();
-        let mut model = this.model.lock().unwrap();
+        let mut this = this.borrow_mut();
+        let Some(inner) = this.inner.as_mut() else {
+            return cx.throw_error("Model has been freed");
+        };
+
+        match param_num {
+            ENHANCEMENT_PARAM_VOICE_GATE_THRESHOLD => {
+                inner.gate.lock().unwrap().gate.threshold = value.clamp(0.0, 1.0);
+                return Ok(cx.undefined());
+            }
+            ENHANCEMENT_PARAM_VOICE_GATE_HOLD_MS => {
+                inner.gate.lock().unwrap().gate.hold_ms = value.max(0.0);
+                return Ok(cx.undefined());
+            }
+            _ => {}
+        }
+
+        let parameter = parse_enhancement_parameter(&mut cx, parameter_arg)?;
+        let mut model = inner.model.lock().unwrap();
 
         model
             .set_parameter(parameter, value)
@@ -195,10 +517,25 @@ impl JsModel {
     fn js_get_parameter(mut cx: FunctionContext) -> JsResult<JsNumber> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
         let parameter_arg = cx.argument::<JsValue>(1)?;
-        let parameter = parse_enhancement_parameter(&mut cx, parameter_arg)?;
+        let param_num = parameter_arg.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx) as i32;
 
         let this = this.borrow();
-        let model = this.model.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+
+        match param_num {
+            ENHANCEMENT_PARAM_VOICE_GATE_THRESHOLD => {
+                return Ok(cx.number(inner.gate.lock().unwrap().gate.threshold as f64));
+            }
+            ENHANCEMENT_PARAM_VOICE_GATE_HOLD_MS => {
+                return Ok(cx.number(inner.gate.lock().unwrap().gate.hold_ms as f64));
+            }
+            _ => {}
+        }
+
+        let parameter = parse_enhancement_parameter(&mut cx, parameter_arg)?;
+        let model = inner.model.lock().unwrap();
 
         let value = model
             .parameter(parameter)
@@ -209,27 +546,95 @@ impl JsModel {
 
     fn js_process_interleaved(mut cx: FunctionContext) -> JsResult<JsUndefined> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
-        let mut buffer = cx.argument::<JsTypedArray<f32>>(1)?;
+        let buffer_arg = cx.argument::<JsValue>(1)?;
 
-        let this = this.borrow();
-        let mut model = this.model.lock().unwrap();
+        let mut this = this.borrow_mut();
+        let Some(inner) = this.inner.as_mut() else {
+            return cx.throw_error("Model has been freed");
+        };
 
-        // Get mutable slice from the typed array
-        let audio_data = buffer.as_mut_slice(&mut cx);
+        // Fast path: process a Float32Array in place with no conversion or allocation.
+        if let Ok(mut buffer) = buffer_arg.downcast::<JsTypedArray<f32>, _>(&mut cx) {
+            let audio_data = buffer.as_mut_slice(&mut cx);
 
-        model
-            .process_interleaved(audio_data)
-            .or_else(|e| cx.throw_error(format!("Failed to process audio: {}", e)))?;
+            {
+                let mut model = inner.model.lock().unwrap();
+                model
+                    .process_interleaved(audio_data)
+                    .or_else(|e| cx.throw_error(format!("Failed to process audio: {}", e)))?;
+            }
 
-        Ok(cx.undefined())
+            inner.gate.lock().unwrap().apply_voice_gate(audio_data);
+
+            return Ok(cx.undefined());
+        }
+
+        // Int16Array: convert to f32 in the reusable scratch buffer, process, then
+        // write the enhanced samples back as i16 in place.
+        if let Ok(mut buffer) = buffer_arg.downcast::<JsTypedArray<i16>, _>(&mut cx) {
+            let audio_data = buffer.as_mut_slice(&mut cx);
+
+            inner.scratch.clear();
+            inner.scratch.extend(audio_data.iter().map(|&s| i16_to_f32(s)));
+
+            {
+                let mut model = inner.model.lock().unwrap();
+                model
+                    .process_interleaved(&mut inner.scratch)
+                    .or_else(|e| cx.throw_error(format!("Failed to process audio: {}", e)))?;
+            }
+            let gain = inner.gate.lock().unwrap().step_voice_gate();
+            if gain < 1.0 {
+                for sample in inner.scratch.iter_mut() {
+                    *sample *= gain;
+                }
+            }
+
+            for (sample, &converted) in audio_data.iter_mut().zip(inner.scratch.iter()) {
+                *sample = f32_to_i16(converted);
+            }
+
+            return Ok(cx.undefined());
+        }
+
+        // Float64Array: same shape as the Int16 path, minus the fixed-point scaling.
+        if let Ok(mut buffer) = buffer_arg.downcast::<JsTypedArray<f64>, _>(&mut cx) {
+            let audio_data = buffer.as_mut_slice(&mut cx);
+
+            inner.scratch.clear();
+            inner.scratch.extend(audio_data.iter().map(|&s| s as f32));
+
+            {
+                let mut model = inner.model.lock().unwrap();
+                model
+                    .process_interleaved(&mut inner.scratch)
+                    .or_else(|e| cx.throw_error(format!("Failed to process audio: {}", e)))?;
+            }
+            let gain = inner.gate.lock().unwrap().step_voice_gate();
+            if gain < 1.0 {
+                for sample in inner.scratch.iter_mut() {
+                    *sample *= gain;
+                }
+            }
+
+            for (sample, &converted) in audio_data.iter_mut().zip(inner.scratch.iter()) {
+                *sample = converted as f64;
+            }
+
+            return Ok(cx.undefined());
+        }
+
+        cx.throw_error("Expected a Float32Array, Int16Array, or Float64Array")
     }
 
     fn js_process_planar(mut cx: FunctionContext) -> JsResult<JsUndefined> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
         let buffers = cx.argument::<JsArray>(1)?;
 
-        let this = this.borrow();
-        let mut model = this.model.lock().unwrap();
+        let mut this = this.borrow_mut();
+        let Some(inner) = this.inner.as_mut() else {
+            return cx.throw_error("Model has been freed");
+        };
 
         // Convert JS array of typed arrays to fixed-size array (max 16 channels)
         let length = buffers.len(&mut cx);
@@ -238,7 +643,96 @@ impl JsModel {
         if length > 16 {
             return cx.throw_error("Maximum 16 channels supported for planar processing");
         }
+        if length == 0 {
+            return Ok(cx.undefined());
+        }
+
+        // All channels are expected to share one sample format; detect it from the
+        // first channel, same as the per-argument detection in `js_process_interleaved`.
+        let first_channel: Handle<JsValue> = buffers.get(&mut cx, 0)?;
+
+        // Int16Array: convert every channel into `planar_scratch`, process, then write
+        // the enhanced samples back as i16 in place.
+        if first_channel.downcast::<JsTypedArray<i16>, _>(&mut cx).is_ok() {
+            inner.planar_scratch.resize_with(length as usize, Vec::new);
+
+            for i in 0..length {
+                let buffer: Handle<JsTypedArray<i16>> = buffers.get(&mut cx, i)?;
+                let data = buffer.as_slice(&cx);
+                inner.planar_scratch[i as usize].clear();
+                inner.planar_scratch[i as usize].extend(data.iter().map(|&s| i16_to_f32(s)));
+            }
+
+            {
+                let mut model = inner.model.lock().unwrap();
+                let mut slice_refs: Vec<&mut [f32]> =
+                    inner.planar_scratch.iter_mut().map(|c| c.as_mut_slice()).collect();
+                model
+                    .process_planar(&mut slice_refs)
+                    .or_else(|e| cx.throw_error(format!("Failed to process planar audio: {}", e)))?;
+            }
+
+            let gain = inner.gate.lock().unwrap().step_voice_gate();
+            if gain < 1.0 {
+                for channel in inner.planar_scratch.iter_mut() {
+                    for sample in channel.iter_mut() {
+                        *sample *= gain;
+                    }
+                }
+            }
+
+            for i in 0..length {
+                let mut buffer: Handle<JsTypedArray<i16>> = buffers.get(&mut cx, i)?;
+                let data = buffer.as_mut_slice(&mut cx);
+                for (sample, &converted) in data.iter_mut().zip(inner.planar_scratch[i as usize].iter()) {
+                    *sample = f32_to_i16(converted);
+                }
+            }
+
+            return Ok(cx.undefined());
+        }
+
+        // Float64Array: same shape as the Int16 path, minus the fixed-point scaling.
+        if first_channel.downcast::<JsTypedArray<f64>, _>(&mut cx).is_ok() {
+            inner.planar_scratch.resize_with(length as usize, Vec::new);
+
+            for i in 0..length {
+                let buffer: Handle<JsTypedArray<f64>> = buffers.get(&mut cx, i)?;
+                let data = buffer.as_slice(&cx);
+                inner.planar_scratch[i as usize].clear();
+                inner.planar_scratch[i as usize].extend(data.iter().map(|&s| s as f32));
+            }
+
+            {
+                let mut model = inner.model.lock().unwrap();
+                let mut slice_refs: Vec<&mut [f32]> =
+                    inner.planar_scratch.iter_mut().map(|c| c.as_mut_slice()).collect();
+                model
+                    .process_planar(&mut slice_refs)
+                    .or_else(|e| cx.throw_error(format!("Failed to process planar audio: {}", e)))?;
+            }
+
+            let gain = inner.gate.lock().unwrap().step_voice_gate();
+            if gain < 1.0 {
+                for channel in inner.planar_scratch.iter_mut() {
+                    for sample in channel.iter_mut() {
+                        *sample *= gain;
+                    }
+                }
+            }
+
+            for i in 0..length {
+                let mut buffer: Handle<JsTypedArray<f64>> = buffers.get(&mut cx, i)?;
+                let data = buffer.as_mut_slice(&mut cx);
+                for (sample, &converted) in data.iter_mut().zip(inner.planar_scratch[i as usize].iter()) {
+                    *sample = converted as f64;
+                }
+            }
+
+            return Ok(cx.undefined());
+        }
 
+        // Fast path: process Float32Arrays in place with no conversion or allocation.
         // Use fixed-size arrays to avoid heap allocation
         let mut handles: [Option<Handle<JsTypedArray<f32>>>; 16] = Default::default();
 
@@ -273,32 +767,314 @@ impl JsModel {
         // Use only the initialized portion of the array
         let slice_refs = &mut slice_array[..length as usize];
 
-        model
-            .process_planar(slice_refs)
-            .or_else(|e| cx.throw_error(format!("Failed to process planar audio: {}", e)))?;
+        {
+            let mut model = inner.model.lock().unwrap();
+            model
+                .process_planar(slice_refs)
+                .or_else(|e| cx.throw_error(format!("Failed to process planar audio: {}", e)))?;
+        }
+
+        let gain = inner.gate.lock().unwrap().step_voice_gate();
+        if gain < 1.0 {
+            for channel in slice_refs.iter_mut() {
+                for sample in channel.iter_mut() {
+                    *sample *= gain;
+                }
+            }
+        }
 
         Ok(cx.undefined())
     }
 
-    fn js_create_vad(mut cx: FunctionContext) -> JsResult<JsBox<RefCell<JsVad>>> {
+    fn js_process_interleaved_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
         let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
+        let buffer = cx.argument::<JsTypedArray<f32>>(1)?;
+        let data = buffer.as_slice(&cx).to_vec();
+
         let this = this.borrow();
-        let mut model = this.model.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let channel = cx.channel();
+        let (deferred, promise) = cx.promise();
+
+        inner
+            .worker
+            .sender
+            .send(ModelJob::ProcessInterleaved {
+                data,
+                deferred,
+                channel,
+            })
+            .or_else(|_| cx.throw_error("Model worker thread is no longer running"))?;
+
+        Ok(promise)
+    }
 
-        let vad = model.create_vad();
+    fn js_process_planar_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+        let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
+        let buffers = cx.argument::<JsArray>(1)?;
+
+        let length = buffers.len(&mut cx);
+        if length > 16 {
+            return cx.throw_error("Maximum 16 channels supported for planar processing");
+        }
+
+        let mut channels: Vec<Vec<f32>> = Vec::with_capacity(length as usize);
+        for i in 0..length {
+            let handle: Handle<JsTypedArray<f32>> = buffers.get(&mut cx, i)?;
+            channels.push(handle.as_slice(&cx).to_vec());
+        }
+
+        let this = this.borrow();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let channel = cx.channel();
+        let (deferred, promise) = cx.promise();
+
+        inner
+            .worker
+            .sender
+            .send(ModelJob::ProcessPlanar {
+                channels,
+                deferred,
+                channel,
+            })
+            .or_else(|_| cx.throw_error("Model worker thread is no longer running"))?;
+
+        Ok(promise)
+    }
+
+    fn js_create_vad(mut cx: FunctionContext) -> JsResult<JsBox<RefCell<JsVad>>> {
+        let this = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
+        let this = this.borrow();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("Model has been freed");
+        };
+        let vad = {
+            let mut model = inner.model.lock().unwrap();
+            model.create_vad()
+        };
 
         Ok(cx.boxed(RefCell::new(JsVad {
-            vad: Arc::new(Mutex::new(vad)),
+            inner: Some(VadInner {
+                vad: Arc::new(Mutex::new(vad)),
+                model: Arc::clone(&inner.model),
+            }),
+        })))
+    }
+}
+
+// ============================================================================
+// Model Streaming Wrapper
+// ============================================================================
+
+struct SampleAdapter {
+    data: Vec<f32>,
+    capacity: usize,
+    write_pos: usize,
+    read_pos: usize,
+    len: usize,
+}
+
+impl SampleAdapter {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        SampleAdapter {
+            data: vec![0.0; capacity],
+            capacity,
+            write_pos: 0,
+            read_pos: 0,
+            len: 0,
+        }
+    }
+
+    fn available(&self) -> usize {
+        self.len
+    }
+
+    fn free(&self) -> usize {
+        self.capacity - self.len
+    }
+
+    fn write(&mut self, samples: &[f32]) {
+        if samples.len() > self.free() {
+            self.grow(self.len + samples.len());
+        }
+        for &sample in samples {
+            self.data[self.write_pos] = sample;
+            self.write_pos = (self.write_pos + 1) % self.capacity;
+            self.len += 1;
+        }
+    }
+
+    fn peek(&self, out: &mut [f32]) {
+        let mut pos = self.read_pos;
+        for slot in out.iter_mut() {
+            *slot = self.data[pos];
+            pos = (pos + 1) % self.capacity;
+        }
+    }
+
+    fn advance(&mut self, count: usize) {
+        self.read_pos = (self.read_pos + count) % self.capacity;
+        self.len -= count;
+    }
+
+    fn grow(&mut self, min_capacity: usize) {
+        let mut new_capacity = self.capacity;
+        while new_capacity < min_capacity {
+            new_capacity *= 2;
+        }
+
+        let mut new_data = vec![0.0; new_capacity];
+        for i in 0..self.len {
+            new_data[i] = self.data[(self.read_pos + i) % self.capacity];
+        }
+
+        self.data = new_data;
+        self.capacity = new_capacity;
+        self.read_pos = 0;
+        self.write_pos = self.len;
+    }
+}
+
+pub struct JsStreamProcessor {
+    model: Arc<Mutex<AicModel>>,
+    gate: Arc<Mutex<ModelGate>>,
+    num_channels: usize,
+    block_samples: usize,
+    input: SampleAdapter,
+    output: SampleAdapter,
+    scratch: Vec<f32>,
+    delay_remaining: usize,
+}
+
+impl Finalize for JsStreamProcessor {}
+
+impl JsStreamProcessor {
+    fn js_new(mut cx: FunctionContext) -> JsResult<JsBox<RefCell<JsStreamProcessor>>> {
+        let model_handle = cx.argument::<JsBox<RefCell<JsModel>>>(0)?;
+        let sample_rate = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+        let num_channels = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+        let (model, gate) = {
+            let model_handle = model_handle.borrow();
+            let Some(model_inner) = model_handle.inner.as_ref() else {
+                return cx.throw_error("Model has been freed");
+            };
+            (Arc::clone(&model_inner.model), Arc::clone(&model_inner.gate))
+        };
+
+        let (optimal_frames, output_delay) = {
+            let locked = model.lock().unwrap();
+            let optimal_frames = locked
+                .optimal_num_frames(sample_rate)
+                .or_else(|e| cx.throw_error(format!("Failed to get optimal num frames: {}", e)))?;
+            let output_delay = locked
+                .output_delay()
+                .or_else(|e| cx.throw_error(format!("Failed to get output delay: {}", e)))?;
+            (optimal_frames as usize, output_delay as usize)
+        };
+
+        let block_samples = optimal_frames * num_channels;
+
+        Ok(cx.boxed(RefCell::new(JsStreamProcessor {
+            model,
+            gate,
+            num_channels,
+            block_samples,
+            input: SampleAdapter::new(block_samples * 2),
+            output: SampleAdapter::new(block_samples * 2),
+            scratch: vec![0.0; block_samples],
+            delay_remaining: output_delay * num_channels,
         })))
     }
+
+    fn drain_full_blocks(&mut self, cx: &mut FunctionContext) -> NeonResult<()> {
+        while self.input.available() >= self.block_samples {
+            self.input.peek(&mut self.scratch);
+
+            {
+                let mut model = self.model.lock().unwrap();
+                model
+                    .process_interleaved(&mut self.scratch)
+                    .or_else(|e| cx.throw_error(format!("Failed to process audio: {}", e)))?;
+            }
+
+            self.gate.lock().unwrap().apply_voice_gate(&mut self.scratch);
+
+            self.input.advance(self.block_samples);
+            let block = self.scratch.clone();
+            self.output.write(&block);
+        }
+
+        Ok(())
+    }
+
+    fn js_push(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        let this = cx.argument::<JsBox<RefCell<JsStreamProcessor>>>(0)?;
+        let buffer = cx.argument::<JsTypedArray<f32>>(1)?;
+        let samples = buffer.as_slice(&cx).to_vec();
+
+        let mut this = this.borrow_mut();
+        this.input.write(&samples);
+        this.drain_full_blocks(&mut cx)?;
+
+        Ok(cx.undefined())
+    }
+
+    fn js_pull(mut cx: FunctionContext) -> JsResult<JsTypedArray<f32>> {
+        let this = cx.argument::<JsBox<RefCell<JsStreamProcessor>>>(0)?;
+        let max_frames = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+
+        let mut this = this.borrow_mut();
+
+        if this.delay_remaining > 0 {
+            let to_skip = this.delay_remaining.min(this.output.available());
+            this.output.advance(to_skip);
+            this.delay_remaining -= to_skip;
+        }
+
+        let requested = max_frames * this.num_channels;
+        let to_read = this.output.available().min(requested);
+
+        let mut samples = vec![0.0; to_read];
+        this.output.peek(&mut samples);
+        this.output.advance(to_read);
+
+        let mut out = cx.typed_array(to_read)?;
+        out.as_mut_slice(&mut cx).copy_from_slice(&samples);
+        Ok(out)
+    }
+
+    fn js_flush(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        let this = cx.argument::<JsBox<RefCell<JsStreamProcessor>>>(0)?;
+        let mut this = this.borrow_mut();
+
+        let remainder = this.input.available() % this.block_samples;
+        if remainder != 0 {
+            let padding = vec![0.0; this.block_samples - remainder];
+            this.input.write(&padding);
+        }
+        this.drain_full_blocks(&mut cx)?;
+
+        Ok(cx.undefined())
+    }
 }
 
 // ============================================================================
 // VAD Class
 // ============================================================================
 
-pub struct JsVad {
+struct VadInner {
     vad: Arc<Mutex<AicVad>>,
+    model: Arc<Mutex<AicModel>>,
+}
+
+pub struct JsVad {
+    inner: Option<VadInner>,
 }
 
 impl Finalize for JsVad {}
@@ -307,13 +1083,29 @@ impl JsVad {
     fn js_is_speech_detected(mut cx: FunctionContext) -> JsResult<JsBoolean> {
         let this = cx.argument::<JsBox<RefCell<JsVad>>>(0)?;
         let this = this.borrow();
-        let vad = this.vad.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("VAD has been freed");
+        };
+        let vad = inner.vad.lock().unwrap();
 
         let detected = vad.is_speech_detected();
 
         Ok(cx.boolean(detected))
     }
 
+    fn js_get_probability(mut cx: FunctionContext) -> JsResult<JsNumber> {
+        let this = cx.argument::<JsBox<RefCell<JsVad>>>(0)?;
+        let this = this.borrow();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("VAD has been freed");
+        };
+        let vad = inner.vad.lock().unwrap();
+
+        let probability = vad.speech_probability();
+
+        Ok(cx.number(probability as f64))
+    }
+
     fn js_set_parameter(mut cx: FunctionContext) -> JsResult<JsUndefined> {
         let this = cx.argument::<JsBox<RefCell<JsVad>>>(0)?;
         let parameter_arg = cx.argument::<JsValue>(1)?;
@@ -321,7 +1113,10 @@ impl JsVad {
         let value = cx.argument::<JsNumber>(2)?.value(&mut cx) as f32;
 
         let this = this.borrow();
-        let mut vad = this.vad.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("VAD has been freed");
+        };
+        let mut vad = inner.vad.lock().unwrap();
 
         vad.set_parameter(parameter, value)
             .or_else(|e| cx.throw_error(format!("Failed to set VAD parameter: {}", e)))?;
@@ -335,7 +1130,10 @@ impl JsVad {
         let parameter = parse_vad_parameter(&mut cx, parameter_arg)?;
 
         let this = this.borrow();
-        let vad = this.vad.lock().unwrap();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("VAD has been freed");
+        };
+        let vad = inner.vad.lock().unwrap();
 
         let value = vad
             .parameter(parameter)
@@ -343,6 +1141,53 @@ impl JsVad {
 
         Ok(cx.number(value as f64))
     }
+
+    fn js_free(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        let this = cx.argument::<JsBox<RefCell<JsVad>>>(0)?;
+        this.borrow_mut().inner.take();
+        Ok(cx.undefined())
+    }
+
+    fn js_clone(mut cx: FunctionContext) -> JsResult<JsBox<RefCell<JsVad>>> {
+        let this = cx.argument::<JsBox<RefCell<JsVad>>>(0)?;
+        let this = this.borrow();
+        let Some(inner) = this.inner.as_ref() else {
+            return cx.throw_error("VAD has been freed");
+        };
+
+        let (speech_hold_duration, sensitivity, minimum_speech_duration) = {
+            let vad = inner.vad.lock().unwrap();
+            (
+                vad.parameter(VadParameter::SpeechHoldDuration)
+                    .or_else(|e| cx.throw_error(format!("Failed to get VAD parameter: {}", e)))?,
+                vad.parameter(VadParameter::Sensitivity)
+                    .or_else(|e| cx.throw_error(format!("Failed to get VAD parameter: {}", e)))?,
+                vad.parameter(VadParameter::MinimumSpeechDuration)
+                    .or_else(|e| cx.throw_error(format!("Failed to get VAD parameter: {}", e)))?,
+            )
+        };
+
+        let mut cloned_vad = {
+            let mut model = inner.model.lock().unwrap();
+            model.create_vad()
+        };
+        cloned_vad
+            .set_parameter(VadParameter::SpeechHoldDuration, speech_hold_duration)
+            .or_else(|e| cx.throw_error(format!("Failed to set VAD parameter: {}", e)))?;
+        cloned_vad
+            .set_parameter(VadParameter::Sensitivity, sensitivity)
+            .or_else(|e| cx.throw_error(format!("Failed to set VAD parameter: {}", e)))?;
+        cloned_vad
+            .set_parameter(VadParameter::MinimumSpeechDuration, minimum_speech_duration)
+            .or_else(|e| cx.throw_error(format!("Failed to set VAD parameter: {}", e)))?;
+
+        Ok(cx.boxed(RefCell::new(JsVad {
+            inner: Some(VadInner {
+                vad: Arc::new(Mutex::new(cloned_vad)),
+                model: Arc::clone(&inner.model),
+            }),
+        })))
+    }
 }
 
 // ============================================================================
@@ -365,12 +1210,31 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("modelGetParameter", JsModel::js_get_parameter)?;
     cx.export_function("modelProcessInterleaved", JsModel::js_process_interleaved)?;
     cx.export_function("modelProcessPlanar", JsModel::js_process_planar)?;
+    cx.export_function(
+        "modelProcessInterleavedAsync",
+        JsModel::js_process_interleaved_async,
+    )?;
+    cx.export_function(
+        "modelProcessPlanarAsync",
+        JsModel::js_process_planar_async,
+    )?;
     cx.export_function("modelCreateVad", JsModel::js_create_vad)?;
+    cx.export_function("modelFree", JsModel::js_free)?;
+    cx.export_function("modelClone", JsModel::js_clone)?;
+
+    // Export model streaming wrapper
+    cx.export_function("streamProcessorNew", JsStreamProcessor::js_new)?;
+    cx.export_function("streamProcessorPush", JsStreamProcessor::js_push)?;
+    cx.export_function("streamProcessorPull", JsStreamProcessor::js_pull)?;
+    cx.export_function("streamProcessorFlush", JsStreamProcessor::js_flush)?;
 
     // Export VAD class
     cx.export_function("vadIsSpeechDetected", JsVad::js_is_speech_detected)?;
+    cx.export_function("vadGetProbability", JsVad::js_get_probability)?;
     cx.export_function("vadSetParameter", JsVad::js_set_parameter)?;
     cx.export_function("vadGetParameter", JsVad::js_get_parameter)?;
+    cx.export_function("vadFree", JsVad::js_free)?;
+    cx.export_function("vadClone", JsVad::js_clone)?;
 
     // Export enhancement parameter constants
     let bypass = cx.number(ENHANCEMENT_PARAM_BYPASS);
@@ -379,6 +1243,13 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_value("ENHANCEMENT_PARAM_ENHANCEMENT_LEVEL", enhancement_level)?;
     let voice_gain = cx.number(ENHANCEMENT_PARAM_VOICE_GAIN);
     cx.export_value("ENHANCEMENT_PARAM_VOICE_GAIN", voice_gain)?;
+    let voice_gate_threshold = cx.number(ENHANCEMENT_PARAM_VOICE_GATE_THRESHOLD);
+    cx.export_value(
+        "ENHANCEMENT_PARAM_VOICE_GATE_THRESHOLD",
+        voice_gate_threshold,
+    )?;
+    let voice_gate_hold_ms = cx.number(ENHANCEMENT_PARAM_VOICE_GATE_HOLD_MS);
+    cx.export_value("ENHANCEMENT_PARAM_VOICE_GATE_HOLD_MS", voice_gate_hold_ms)?;
 
     // Export VAD parameter constants
     let lookback = cx.number(VAD_PARAM_SPEECH_HOLD_DURATION);
@@ -388,5 +1259,14 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     let min_speech_duration = cx.number(VAD_PARAM_MINIMUM_SPEECH_DURATION);
     cx.export_value("VAD_PARAM_MINIMUM_SPEECH_DURATION", min_speech_duration)?;
 
+    // Export the split Model/Processor API
+    model::register_exports(&mut cx)?;
+    processor::register_exports(&mut cx)?;
+    processor_context::register_exports(&mut cx)?;
+    sample_format::register_exports(&mut cx)?;
+    stream_processor::register_exports(&mut cx)?;
+    vad_context::register_exports(&mut cx)?;
+    wav_file::register_exports(&mut cx)?;
+
     Ok(())
 }
@@ -6,17 +6,19 @@ use neon::{
     prelude::{Context, FunctionContext},
     result::{JsResult, NeonResult},
     types::{
-        Finalize, JsArray, JsBoolean, JsBox, JsNumber, JsString, JsTypedArray, JsUndefined,
-        buffer::TypedArray,
+        Finalize, JsArray, JsBoolean, JsBox, JsNumber, JsPromise, JsString, JsTypedArray,
+        JsUndefined, JsValue, buffer::TypedArray,
     },
 };
 
 use crate::model::Model;
-use crate::processor_context::ProcessorContext;
+use crate::processor_context::{ProcessorContext, RampState};
+use crate::sample_format::{self, SampleFormat};
 use crate::vad_context::VadContext;
 
 pub struct Processor {
     inner: Arc<Mutex<aic_sdk::Processor<'static>>>,
+    ramps: Arc<Mutex<RampState>>,
 }
 
 impl Finalize for Processor {
@@ -38,6 +40,7 @@ impl Processor {
 
         Ok(cx.boxed(Processor {
             inner: Arc::new(Mutex::new(processor)),
+            ramps: Arc::new(Mutex::new(RampState::new())),
         }))
     }
 
@@ -61,6 +64,8 @@ impl Processor {
             .initialize(&config)
             .or_else(|e| cx.throw_error(e.to_string()))?;
 
+        this.ramps.lock().unwrap().configure(sample_rate, num_frames);
+
         Ok(cx.undefined())
     }
 
@@ -69,6 +74,7 @@ impl Processor {
         let mut buffer = cx.argument::<JsTypedArray<f32>>(1)?;
 
         let mut processor = this.inner.lock().unwrap();
+        this.ramps.lock().unwrap().step_all(&mut processor);
 
         let audio_data = buffer.as_mut_slice(&mut cx);
 
@@ -84,6 +90,7 @@ impl Processor {
         let mut buffer = cx.argument::<JsTypedArray<f32>>(1)?;
 
         let mut processor = this.inner.lock().unwrap();
+        this.ramps.lock().unwrap().step_all(&mut processor);
 
         let audio_data = buffer.as_mut_slice(&mut cx);
 
@@ -99,6 +106,7 @@ impl Processor {
         let buffers = cx.argument::<JsArray>(1)?;
 
         let mut processor = this.inner.lock().unwrap();
+        this.ramps.lock().unwrap().step_all(&mut processor);
 
         let length = buffers.len(&mut cx);
 
@@ -144,13 +152,119 @@ impl Processor {
         Ok(cx.undefined())
     }
 
+    pub fn process_interleaved_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+        let this = cx.argument::<JsBox<Processor>>(0)?;
+        let buffer = cx.argument::<JsTypedArray<f32>>(1)?;
+        let mut data = buffer.as_slice(&cx).to_vec();
+
+        let inner = Arc::clone(&this.inner);
+        let ramps = Arc::clone(&this.ramps);
+
+        let promise = cx
+            .task(move || {
+                let mut processor = inner.lock().unwrap();
+                ramps.lock().unwrap().step_all(&mut processor);
+                processor
+                    .process_interleaved(&mut data)
+                    .map(|_| data)
+                    .map_err(|e| e.to_string())
+            })
+            .promise(move |mut cx, result| match result {
+                Ok(data) => {
+                    let mut out = JsTypedArray::<f32>::new(&mut cx, data.len())?;
+                    out.as_mut_slice(&mut cx).copy_from_slice(&data);
+                    Ok(out)
+                }
+                Err(e) => cx.throw_error(e),
+            });
+
+        Ok(promise)
+    }
+
+    pub fn process_sequential_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+        let this = cx.argument::<JsBox<Processor>>(0)?;
+        let buffer = cx.argument::<JsTypedArray<f32>>(1)?;
+        let mut data = buffer.as_slice(&cx).to_vec();
+
+        let inner = Arc::clone(&this.inner);
+        let ramps = Arc::clone(&this.ramps);
+
+        let promise = cx
+            .task(move || {
+                let mut processor = inner.lock().unwrap();
+                ramps.lock().unwrap().step_all(&mut processor);
+                processor
+                    .process_sequential(&mut data)
+                    .map(|_| data)
+                    .map_err(|e| e.to_string())
+            })
+            .promise(move |mut cx, result| match result {
+                Ok(data) => {
+                    let mut out = JsTypedArray::<f32>::new(&mut cx, data.len())?;
+                    out.as_mut_slice(&mut cx).copy_from_slice(&data);
+                    Ok(out)
+                }
+                Err(e) => cx.throw_error(e),
+            });
+
+        Ok(promise)
+    }
+
+    pub fn process_planar_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+        let this = cx.argument::<JsBox<Processor>>(0)?;
+        let buffers = cx.argument::<JsArray>(1)?;
+
+        let length = buffers.len(&mut cx);
+        if length > 16 {
+            return cx.throw_error("Maximum 16 channels supported for planar processing");
+        }
+
+        let mut channels: Vec<Vec<f32>> = Vec::with_capacity(length as usize);
+        for i in 0..length {
+            let handle: Handle<JsTypedArray<f32>> = buffers.get(&mut cx, i)?;
+            channels.push(handle.as_slice(&cx).to_vec());
+        }
+
+        let inner = Arc::clone(&this.inner);
+        let ramps = Arc::clone(&this.ramps);
+
+        let promise = cx
+            .task(move || {
+                let mut processor = inner.lock().unwrap();
+                ramps.lock().unwrap().step_all(&mut processor);
+                let mut slice_refs: Vec<&mut [f32]> =
+                    channels.iter_mut().map(|c| c.as_mut_slice()).collect();
+                processor
+                    .process_planar(&mut slice_refs)
+                    .map(|_| channels)
+                    .map_err(|e| e.to_string())
+            })
+            .promise(move |mut cx, result| match result {
+                Ok(channels) => {
+                    let out = JsArray::new(&mut cx, channels.len());
+                    for (i, chan) in channels.iter().enumerate() {
+                        let mut out_chan = JsTypedArray::<f32>::new(&mut cx, chan.len())?;
+                        out_chan.as_mut_slice(&mut cx).copy_from_slice(chan);
+                        out.set(&mut cx, i as u32, out_chan)?;
+                    }
+                    Ok(out)
+                }
+                Err(e) => cx.throw_error(e),
+            });
+
+        Ok(promise)
+    }
+
     pub fn get_processor_context(mut cx: FunctionContext) -> JsResult<JsBox<ProcessorContext>> {
         let this = cx.argument::<JsBox<Processor>>(0)?;
         let processor = this.inner.lock().unwrap();
 
         let context = processor.processor_context();
 
-        Ok(cx.boxed(ProcessorContext { inner: context }))
+        Ok(cx.boxed(ProcessorContext {
+            inner: context,
+            ramps: Arc::clone(&this.ramps),
+        }))
     }
 
     pub fn get_vad_context(mut cx: FunctionContext) -> JsResult<JsBox<VadContext>> {
@@ -161,6 +275,65 @@ impl Processor {
 
         Ok(cx.boxed(VadContext { inner: context }))
     }
+
+    pub(crate) fn share(&self) -> Arc<Mutex<aic_sdk::Processor<'static>>> {
+        Arc::clone(&self.inner)
+    }
+
+    pub fn process_interleaved_typed(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        let this = cx.argument::<JsBox<Processor>>(0)?;
+        let buffer_arg = cx.argument::<JsValue>(1)?;
+        let format_arg = cx.argument::<JsValue>(2)?;
+        let format = sample_format::parse_sample_format(&mut cx, format_arg)?;
+
+        let mut processor = this.inner.lock().unwrap();
+        this.ramps.lock().unwrap().step_all(&mut processor);
+
+        match format {
+            SampleFormat::Int16 => {
+                let mut buffer = buffer_arg.downcast_or_throw::<JsTypedArray<i16>, _>(&mut cx)?;
+                let data = buffer.as_mut_slice(&mut cx);
+
+                let mut scratch: Vec<f32> =
+                    data.iter().map(|&s| sample_format::i16_to_f32(s)).collect();
+
+                processor
+                    .process_interleaved(&mut scratch)
+                    .or_else(|e| cx.throw_error(e.to_string()))?;
+
+                for (sample, &processed) in data.iter_mut().zip(scratch.iter()) {
+                    *sample = sample_format::f32_to_i16(processed);
+                }
+            }
+            SampleFormat::Int24In32 => {
+                let mut buffer = buffer_arg.downcast_or_throw::<JsTypedArray<i32>, _>(&mut cx)?;
+                let data = buffer.as_mut_slice(&mut cx);
+
+                let mut scratch: Vec<f32> = data
+                    .iter()
+                    .map(|&s| sample_format::i24in32_to_f32(s))
+                    .collect();
+
+                processor
+                    .process_interleaved(&mut scratch)
+                    .or_else(|e| cx.throw_error(e.to_string()))?;
+
+                for (sample, &processed) in data.iter_mut().zip(scratch.iter()) {
+                    *sample = sample_format::f32_to_i24in32(processed);
+                }
+            }
+            SampleFormat::Float32 => {
+                let mut buffer = buffer_arg.downcast_or_throw::<JsTypedArray<f32>, _>(&mut cx)?;
+                let data = buffer.as_mut_slice(&mut cx);
+
+                processor
+                    .process_interleaved(data)
+                    .or_else(|e| cx.throw_error(e.to_string()))?;
+            }
+        }
+
+        Ok(cx.undefined())
+    }
 }
 
 pub fn register_exports(cx: &mut neon::prelude::ModuleContext) -> NeonResult<()> {
@@ -172,6 +345,22 @@ pub fn register_exports(cx: &mut neon::prelude::ModuleContext) -> NeonResult<()>
     )?;
     cx.export_function("processorProcessSequential", Processor::process_sequential)?;
     cx.export_function("processorProcessPlanar", Processor::process_planar)?;
+    cx.export_function(
+        "processorProcessInterleavedAsync",
+        Processor::process_interleaved_async,
+    )?;
+    cx.export_function(
+        "processorProcessSequentialAsync",
+        Processor::process_sequential_async,
+    )?;
+    cx.export_function(
+        "processorProcessPlanarAsync",
+        Processor::process_planar_async,
+    )?;
+    cx.export_function(
+        "processorProcessInterleavedTyped",
+        Processor::process_interleaved_typed,
+    )?;
     cx.export_function(
         "processorGetProcessorContext",
         Processor::get_processor_context,
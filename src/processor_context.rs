@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+
 use neon::{
     handle::Handle,
     prelude::{Context, FunctionContext},
@@ -24,8 +26,71 @@ pub fn parse_processor_parameter(
     }
 }
 
+fn processor_parameter_id(parameter: aic_sdk::ProcessorParameter) -> i32 {
+    match parameter {
+        aic_sdk::ProcessorParameter::Bypass => PROCESSOR_PARAM_BYPASS,
+        aic_sdk::ProcessorParameter::EnhancementLevel => PROCESSOR_PARAM_ENHANCEMENT_LEVEL,
+        aic_sdk::ProcessorParameter::VoiceGain => PROCESSOR_PARAM_VOICE_GAIN,
+    }
+}
+
+fn processor_parameter_from_id(param_id: i32) -> aic_sdk::ProcessorParameter {
+    match param_id {
+        PROCESSOR_PARAM_BYPASS => aic_sdk::ProcessorParameter::Bypass,
+        PROCESSOR_PARAM_ENHANCEMENT_LEVEL => aic_sdk::ProcessorParameter::EnhancementLevel,
+        PROCESSOR_PARAM_VOICE_GAIN => aic_sdk::ProcessorParameter::VoiceGain,
+        _ => unreachable!("param_id is always produced by processor_parameter_id"),
+    }
+}
+
+struct ParamRamp {
+    param_id: i32,
+    current: f32,
+    target: f32,
+    increment: f32,
+}
+
+pub struct RampState {
+    pub(crate) sample_rate: u32,
+    pub(crate) num_frames: usize,
+    active: Vec<ParamRamp>,
+}
+
+impl RampState {
+    pub(crate) fn new() -> Self {
+        RampState {
+            sample_rate: 0,
+            num_frames: 0,
+            active: Vec::new(),
+        }
+    }
+
+    pub(crate) fn configure(&mut self, sample_rate: u32, num_frames: usize) {
+        self.sample_rate = sample_rate;
+        self.num_frames = num_frames;
+        self.active.clear();
+    }
+
+    pub(crate) fn step_all(&mut self, processor: &mut aic_sdk::Processor<'static>) {
+        self.active.retain_mut(|ramp| {
+            ramp.current += ramp.increment;
+            let reached = if ramp.increment >= 0.0 {
+                ramp.current >= ramp.target
+            } else {
+                ramp.current <= ramp.target
+            };
+            let value = if reached { ramp.target } else { ramp.current };
+
+            let _ = processor.set_parameter(processor_parameter_from_id(ramp.param_id), value);
+
+            !reached
+        });
+    }
+}
+
 pub struct ProcessorContext {
     pub(crate) inner: aic_sdk::ProcessorContext,
+    pub(crate) ramps: Arc<Mutex<RampState>>,
 }
 
 impl Finalize for ProcessorContext {
@@ -72,6 +137,39 @@ impl ProcessorContext {
         let delay = this.inner.output_delay();
         Ok(cx.number(delay as f64))
     }
+
+    pub fn set_parameter_ramp(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        let this = cx.argument::<JsBox<ProcessorContext>>(0)?;
+        let parameter_arg = cx.argument::<JsValue>(1)?;
+        let parameter = parse_processor_parameter(&mut cx, parameter_arg)?;
+        let target_value = cx.argument::<JsNumber>(2)?.value(&mut cx) as f32;
+        let duration_ms = cx.argument::<JsNumber>(3)?.value(&mut cx);
+
+        let current_value = this
+            .inner
+            .parameter(parameter)
+            .or_else(|e| cx.throw_error(e.to_string()))?;
+
+        let mut ramps = this.ramps.lock().unwrap();
+        if ramps.sample_rate == 0 || ramps.num_frames == 0 {
+            return cx.throw_error("Processor must be initialized before ramping a parameter");
+        }
+
+        let blocks_per_ms = ramps.sample_rate as f64 / ramps.num_frames as f64 / 1000.0;
+        let num_blocks = (duration_ms * blocks_per_ms).max(1.0);
+        let increment = (target_value - current_value) / num_blocks as f32;
+
+        let param_id = processor_parameter_id(parameter);
+        ramps.active.retain(|ramp| ramp.param_id != param_id);
+        ramps.active.push(ParamRamp {
+            param_id,
+            current: current_value,
+            target: target_value,
+            increment,
+        });
+
+        Ok(cx.undefined())
+    }
 }
 
 pub fn register_exports(cx: &mut neon::prelude::ModuleContext) -> NeonResult<()> {
@@ -88,6 +186,10 @@ pub fn register_exports(cx: &mut neon::prelude::ModuleContext) -> NeonResult<()>
         "processorContextGetOutputDelay",
         ProcessorContext::get_output_delay,
     )?;
+    cx.export_function(
+        "processorContextSetParameterRamp",
+        ProcessorContext::set_parameter_ramp,
+    )?;
 
     // Export processor parameter constants
     let bypass = cx.number(PROCESSOR_PARAM_BYPASS);
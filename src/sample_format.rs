@@ -0,0 +1,64 @@
+use neon::{
+    handle::Handle,
+    prelude::{Context, FunctionContext},
+    result::NeonResult,
+    types::{JsNumber, JsValue},
+};
+
+pub fn register_exports(cx: &mut neon::prelude::ModuleContext) -> NeonResult<()> {
+    let sixteen_bit = cx.number(AUDIO_SAMPLE_FORMAT_16BIT);
+    cx.export_value("AUDIO_SAMPLE_FORMAT_16BIT", sixteen_bit)?;
+    let twenty_four_in_32 = cx.number(AUDIO_SAMPLE_FORMAT_24BIT_IN32);
+    cx.export_value("AUDIO_SAMPLE_FORMAT_24BIT_IN32", twenty_four_in_32)?;
+    let thirty_two_float = cx.number(AUDIO_SAMPLE_FORMAT_32BIT_FLOAT);
+    cx.export_value("AUDIO_SAMPLE_FORMAT_32BIT_FLOAT", thirty_two_float)?;
+
+    Ok(())
+}
+
+// Audio sample format constants
+pub const AUDIO_SAMPLE_FORMAT_16BIT: i32 = 0;
+pub const AUDIO_SAMPLE_FORMAT_24BIT_IN32: i32 = 1;
+pub const AUDIO_SAMPLE_FORMAT_32BIT_FLOAT: i32 = 2;
+
+#[derive(Clone, Copy)]
+pub enum SampleFormat {
+    Int16,
+    Int24In32,
+    Float32,
+}
+
+pub fn parse_sample_format(
+    cx: &mut FunctionContext,
+    value: Handle<JsValue>,
+) -> NeonResult<SampleFormat> {
+    let format_num = value.downcast_or_throw::<JsNumber, _>(cx)?.value(cx) as i32;
+
+    match format_num {
+        AUDIO_SAMPLE_FORMAT_16BIT => Ok(SampleFormat::Int16),
+        AUDIO_SAMPLE_FORMAT_24BIT_IN32 => Ok(SampleFormat::Int24In32),
+        AUDIO_SAMPLE_FORMAT_32BIT_FLOAT => Ok(SampleFormat::Float32),
+        _ => cx.throw_error(format!("Invalid sample format: {}", format_num)),
+    }
+}
+
+/// Converts a 16-bit PCM sample to `f32` in `[-1.0, 1.0]`.
+pub fn i16_to_f32(sample: i16) -> f32 {
+    sample as f32 / 32768.0
+}
+
+/// Converts an `f32` sample in `[-1.0, 1.0]` to 16-bit PCM, rounding and clamping.
+pub fn f32_to_i16(sample: f32) -> i16 {
+    (sample * 32768.0).round().clamp(i16::MIN as f32, i16::MAX as f32) as i16
+}
+
+/// Converts a 24-bit-in-32 PCM sample (sign-extended from the low 24 bits) to `f32`.
+pub fn i24in32_to_f32(sample: i32) -> f32 {
+    let sign_extended = (sample << 8) >> 8;
+    sign_extended as f32 / 8_388_608.0
+}
+
+/// Converts an `f32` sample in `[-1.0, 1.0]` to a 24-bit-in-32 PCM sample, rounding and clamping.
+pub fn f32_to_i24in32(sample: f32) -> i32 {
+    (sample * 8_388_608.0).round().clamp(-8_388_608.0, 8_388_607.0) as i32
+}
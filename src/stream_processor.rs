@@ -0,0 +1,195 @@
+use std::sync::{Arc, Mutex};
+
+use neon::{
+    prelude::{Context, FunctionContext},
+    result::{JsResult, NeonResult},
+    types::{Finalize, JsBox, JsNumber, JsTypedArray, JsUndefined, buffer::TypedArray},
+};
+
+use crate::processor::Processor;
+
+struct RingBuffer {
+    data: Vec<f32>,
+    capacity: usize,
+    write_pos: usize,
+    read_pos: usize,
+    len: usize,
+}
+
+impl RingBuffer {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        RingBuffer {
+            data: vec![0.0; capacity],
+            capacity,
+            write_pos: 0,
+            read_pos: 0,
+            len: 0,
+        }
+    }
+
+    fn available(&self) -> usize {
+        self.len
+    }
+
+    fn free(&self) -> usize {
+        self.capacity - self.len
+    }
+
+    fn write(&mut self, samples: &[f32]) {
+        if samples.len() > self.free() {
+            self.grow(self.len + samples.len());
+        }
+        for &sample in samples {
+            self.data[self.write_pos] = sample;
+            self.write_pos = (self.write_pos + 1) % self.capacity;
+            self.len += 1;
+        }
+    }
+
+    fn peek(&self, out: &mut [f32]) {
+        let mut pos = self.read_pos;
+        for slot in out.iter_mut() {
+            *slot = self.data[pos];
+            pos = (pos + 1) % self.capacity;
+        }
+    }
+
+    fn advance(&mut self, count: usize) {
+        self.read_pos = (self.read_pos + count) % self.capacity;
+        self.len -= count;
+    }
+
+    fn grow(&mut self, min_capacity: usize) {
+        let mut new_capacity = self.capacity;
+        while new_capacity < min_capacity {
+            new_capacity *= 2;
+        }
+
+        let mut new_data = vec![0.0; new_capacity];
+        for i in 0..self.len {
+            new_data[i] = self.data[(self.read_pos + i) % self.capacity];
+        }
+
+        self.data = new_data;
+        self.capacity = new_capacity;
+        self.read_pos = 0;
+        self.write_pos = self.len;
+    }
+}
+
+struct StreamState {
+    num_channels: usize,
+    block_samples: usize,
+    input: RingBuffer,
+    output: RingBuffer,
+    scratch: Vec<f32>,
+    delay_remaining: usize,
+}
+
+pub struct StreamProcessor {
+    processor: Arc<Mutex<aic_sdk::Processor<'static>>>,
+    state: Mutex<StreamState>,
+}
+
+impl Finalize for StreamProcessor {}
+
+impl StreamProcessor {
+    pub fn new(mut cx: FunctionContext) -> JsResult<JsBox<StreamProcessor>> {
+        let processor_handle = cx.argument::<JsBox<Processor>>(0)?;
+        let num_channels = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+        let num_frames = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+        let block_samples = num_frames * num_channels;
+        let processor = processor_handle.share();
+
+        let delay_remaining = {
+            let locked = processor.lock().unwrap();
+            locked.processor_context().output_delay() as usize * num_channels
+        };
+
+        Ok(cx.boxed(StreamProcessor {
+            processor,
+            state: Mutex::new(StreamState {
+                num_channels,
+                block_samples,
+                input: RingBuffer::new(block_samples * 2),
+                output: RingBuffer::new(block_samples * 2),
+                scratch: vec![0.0; block_samples],
+                delay_remaining,
+            }),
+        }))
+    }
+
+    pub fn push(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+        let this = cx.argument::<JsBox<StreamProcessor>>(0)?;
+        let buffer = cx.argument::<JsTypedArray<f32>>(1)?;
+        let samples = buffer.as_slice(&cx).to_vec();
+
+        let mut state = this.state.lock().unwrap();
+        state.input.write(&samples);
+
+        let block_samples = state.block_samples;
+        while state.input.available() >= block_samples {
+            state.input.peek(&mut state.scratch);
+
+            {
+                let mut processor = this.processor.lock().unwrap();
+                processor
+                    .process_interleaved(&mut state.scratch)
+                    .or_else(|e| cx.throw_error(e.to_string()))?;
+            }
+
+            state.input.advance(block_samples);
+            let block = state.scratch.clone();
+            state.output.write(&block);
+        }
+
+        Ok(cx.undefined())
+    }
+
+    pub fn available_output(mut cx: FunctionContext) -> JsResult<JsNumber> {
+        let this = cx.argument::<JsBox<StreamProcessor>>(0)?;
+        let state = this.state.lock().unwrap();
+
+        let available = state.output.available().saturating_sub(state.delay_remaining);
+        Ok(cx.number((available / state.num_channels) as f64))
+    }
+
+    pub fn pull(mut cx: FunctionContext) -> JsResult<JsTypedArray<f32>> {
+        let this = cx.argument::<JsBox<StreamProcessor>>(0)?;
+        let max_frames = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+
+        let mut state = this.state.lock().unwrap();
+
+        if state.delay_remaining > 0 {
+            let to_skip = state.delay_remaining.min(state.output.available());
+            state.output.advance(to_skip);
+            state.delay_remaining -= to_skip;
+        }
+
+        let num_channels = state.num_channels;
+        let requested = max_frames * num_channels;
+        let to_read = state.output.available().min(requested);
+
+        let mut samples = vec![0.0; to_read];
+        state.output.peek(&mut samples);
+        state.output.advance(to_read);
+
+        let mut out = JsTypedArray::<f32>::new(&mut cx, to_read)?;
+        out.as_mut_slice(&mut cx).copy_from_slice(&samples);
+        Ok(out)
+    }
+}
+
+pub fn register_exports(cx: &mut neon::prelude::ModuleContext) -> NeonResult<()> {
+    cx.export_function("processorStreamNew", StreamProcessor::new)?;
+    cx.export_function("processorStreamPush", StreamProcessor::push)?;
+    cx.export_function(
+        "processorStreamAvailableOutput",
+        StreamProcessor::available_output,
+    )?;
+    cx.export_function("processorStreamPull", StreamProcessor::pull)?;
+
+    Ok(())
+}
@@ -0,0 +1,241 @@
+use std::fs;
+
+use neon::{
+    prelude::{Context, FunctionContext},
+    result::{JsResult, NeonResult},
+    types::{JsBox, JsString, JsUndefined},
+};
+
+use crate::model::Model;
+use crate::sample_format::{f32_to_i16, f32_to_i24in32, i16_to_f32, i24in32_to_f32};
+
+struct WavData {
+    sample_rate: u32,
+    num_channels: u16,
+    bits_per_sample: u16,
+    is_float: bool,
+    samples: Vec<f32>,
+}
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+
+fn read_u16_le(bytes: &[u8]) -> u16 {
+    u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+fn read_u32_le(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_wav(path: &str) -> Result<WavData, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Failed to read WAV file: {}", e))?;
+
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err("Not a valid RIFF/WAVE file".to_string());
+    }
+
+    let mut audio_format = 0u16;
+    let mut num_channels = 0u16;
+    let mut sample_rate = 0u32;
+    let mut bits_per_sample = 0u16;
+    let mut data: &[u8] = &[];
+
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let chunk_id = &bytes[pos..pos + 4];
+        let chunk_size = read_u32_le(&bytes[pos + 4..pos + 8]) as usize;
+        let chunk_start = pos + 8;
+        let chunk_end = (chunk_start + chunk_size).min(bytes.len());
+        let chunk_body = &bytes[chunk_start..chunk_end];
+
+        match chunk_id {
+            b"fmt " => {
+                audio_format = read_u16_le(&chunk_body[0..2]);
+                num_channels = read_u16_le(&chunk_body[2..4]);
+                sample_rate = read_u32_le(&chunk_body[4..8]);
+                bits_per_sample = read_u16_le(&chunk_body[14..16]);
+            }
+            b"data" => {
+                data = chunk_body;
+            }
+            _ => {}
+        }
+
+        // Chunks are word-aligned.
+        pos = chunk_start + chunk_size + (chunk_size % 2);
+    }
+
+    if num_channels == 0 || sample_rate == 0 {
+        return Err("WAV file is missing a fmt chunk".to_string());
+    }
+
+    let is_float = audio_format == WAVE_FORMAT_IEEE_FLOAT;
+    if audio_format != WAVE_FORMAT_PCM && audio_format != WAVE_FORMAT_IEEE_FLOAT {
+        return Err(format!("Unsupported WAV audio format: {}", audio_format));
+    }
+
+    let samples = match (bits_per_sample, is_float) {
+        (16, false) => data
+            .chunks_exact(2)
+            .map(|b| i16_to_f32(i16::from_le_bytes([b[0], b[1]])))
+            .collect(),
+        (24, false) => data
+            .chunks_exact(3)
+            .map(|b| i24in32_to_f32(i32::from_le_bytes([b[0], b[1], b[2], 0])))
+            .collect(),
+        (32, true) => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        _ => {
+            return Err(format!(
+                "Unsupported WAV sample encoding: {} bits, float={}",
+                bits_per_sample, is_float
+            ));
+        }
+    };
+
+    Ok(WavData {
+        sample_rate,
+        num_channels,
+        bits_per_sample,
+        is_float,
+        samples,
+    })
+}
+
+fn write_wav(
+    path: &str,
+    samples: &[f32],
+    sample_rate: u32,
+    num_channels: u16,
+    bits_per_sample: u16,
+    is_float: bool,
+) -> Result<(), String> {
+    let bytes_per_sample = (bits_per_sample / 8) as u32;
+    let block_align = bytes_per_sample as u16 * num_channels;
+    let byte_rate = sample_rate * block_align as u32;
+    let data_size = samples.len() as u32 * bytes_per_sample;
+
+    let mut out = Vec::with_capacity(44 + data_size as usize);
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_size).to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+
+    out.extend_from_slice(b"fmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    let audio_format = if is_float {
+        WAVE_FORMAT_IEEE_FLOAT
+    } else {
+        WAVE_FORMAT_PCM
+    };
+    out.extend_from_slice(&audio_format.to_le_bytes());
+    out.extend_from_slice(&num_channels.to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&byte_rate.to_le_bytes());
+    out.extend_from_slice(&block_align.to_le_bytes());
+    out.extend_from_slice(&bits_per_sample.to_le_bytes());
+
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_size.to_le_bytes());
+
+    match (bits_per_sample, is_float) {
+        (16, false) => {
+            for &sample in samples {
+                out.extend_from_slice(&f32_to_i16(sample).to_le_bytes());
+            }
+        }
+        (24, false) => {
+            for &sample in samples {
+                let bytes = f32_to_i24in32(sample).to_le_bytes();
+                out.extend_from_slice(&bytes[0..3]);
+            }
+        }
+        (32, true) => {
+            for &sample in samples {
+                out.extend_from_slice(&sample.to_le_bytes());
+            }
+        }
+        _ => {
+            return Err(format!(
+                "Unsupported WAV sample encoding: {} bits, float={}",
+                bits_per_sample, is_float
+            ));
+        }
+    }
+
+    fs::write(path, out).map_err(|e| format!("Failed to write WAV file: {}", e))
+}
+
+pub fn process_file(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let input_path = cx.argument::<JsString>(0)?.value(&mut cx);
+    let output_path = cx.argument::<JsString>(1)?.value(&mut cx);
+    let license_key = cx.argument::<JsString>(2)?.value(&mut cx);
+    let model = cx.argument::<JsBox<Model>>(3)?;
+
+    let wav = read_wav(&input_path).or_else(|e| cx.throw_error(e))?;
+
+    // SAFETY: This function has no safety requirements.
+    unsafe {
+        aic_sdk::set_sdk_id(4);
+    }
+
+    let mut processor = aic_sdk::Processor::new(&model.inner, &license_key)
+        .or_else(|e| cx.throw_error(e.to_string()))?;
+
+    let num_frames = model.inner.optimal_num_frames(wav.sample_rate) as usize;
+    let config = aic_sdk::ProcessorConfig {
+        sample_rate: wav.sample_rate,
+        num_channels: wav.num_channels,
+        num_frames,
+        allow_variable_frames: false,
+    };
+    processor
+        .initialize(&config)
+        .or_else(|e| cx.throw_error(e.to_string()))?;
+
+    let output_delay_samples =
+        processor.processor_context().output_delay() as usize * wav.num_channels as usize;
+
+    let block_samples = num_frames * wav.num_channels as usize;
+    let mut samples = wav.samples;
+    let original_len = samples.len();
+
+    // Pad the tail by at least `output_delay_samples` so the processor has enough
+    // input to actually emit the delayed tail, then round up to a full block.
+    let padded_len = original_len + output_delay_samples;
+    let remainder = padded_len % block_samples;
+    let target_len = if remainder == 0 {
+        padded_len
+    } else {
+        padded_len + (block_samples - remainder)
+    };
+    samples.resize(target_len, 0.0);
+
+    for block in samples.chunks_mut(block_samples) {
+        processor
+            .process_interleaved(block)
+            .or_else(|e| cx.throw_error(e.to_string()))?;
+    }
+
+    let trimmed = &samples[output_delay_samples..output_delay_samples + original_len];
+
+    write_wav(
+        &output_path,
+        trimmed,
+        wav.sample_rate,
+        wav.num_channels,
+        wav.bits_per_sample,
+        wav.is_float,
+    )
+    .or_else(|e| cx.throw_error(e))?;
+
+    Ok(cx.undefined())
+}
+
+pub fn register_exports(cx: &mut neon::prelude::ModuleContext) -> NeonResult<()> {
+    cx.export_function("processFile", process_file)?;
+
+    Ok(())
+}